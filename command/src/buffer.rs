@@ -77,6 +77,37 @@ pub struct SimultaneousUse;
 #[derive(Clone, Copy, Debug)]
 pub struct RenderPassContinue;
 
+impl Usage for RenderPassContinue {
+    fn flags(&self) -> UsageFlags {
+        UsageFlags::RENDER_PASS_CONTINUE
+    }
+}
+
+/// Implemented for recording-state buffers that may legally record commands
+/// outside of a render pass.
+///
+/// Every usage implements this except `RenderPassContinue`: a buffer begun
+/// with that usage is considered to run entirely inside a subpass, so
+/// `Encoder` methods that Vulkan forbids within a render pass (dispatch,
+/// copies, clears, barriers, ...) require `Self: OutsideRenderPass` and are
+/// simply not available on it, leaving only `draw`/`bind_graphics_pipeline`.
+pub trait OutsideRenderPass {}
+
+impl<B, C, L, R> OutsideRenderPass for Buffer<B, C, RecordingState<OneShot>, L, R> {}
+impl<B, C, S, L, R> OutsideRenderPass for Buffer<B, C, RecordingState<MultiShot<S>>, L, R> {}
+
+/// Usage types legal to `begin` a primary command buffer with.
+///
+/// Implemented for every `Usage` except `RenderPassContinue`: per Vulkan's
+/// spec that bit "is ignored" on a primary buffer, so accepting it here
+/// would wrongly gate off `dispatch`/copies/clears/blits via
+/// `OutsideRenderPass` (which is implemented per usage type, not per
+/// `(usage, level)`) for no real reason.
+pub trait PrimaryUsage: Usage {}
+
+impl PrimaryUsage for OneShot {}
+impl<S> PrimaryUsage for MultiShot<S> {}
+
 bitflags!{
     /// Bitmask specifying usage behavior for command buffer
     /// See Vulkan docs for detailed info:
@@ -136,13 +167,94 @@ pub struct Buffer<B, C, S, L, R = ()> {
     relevant: Relevant,
 }
 
+impl<B, C, L, R> Buffer<B, C, InitialState, L, R> {
+    /// Wrap a raw command buffer into a `Buffer` in `InitialState`.
+    ///
+    /// This is the only crate-internal way to construct a `Buffer`; code
+    /// elsewhere in the crate that allocates or recycles raw buffers (e.g.
+    /// `CommandPool`) goes through this rather than reaching into private fields.
+    pub(crate) fn from_raw(inner: B, capability: C, level: L, reset: R, family: FamilyId) -> Self {
+        Buffer {
+            inner,
+            capability,
+            state: InitialState,
+            level,
+            reset,
+            family,
+            relevant: Relevant::new(),
+        }
+    }
+
+    /// Take the raw command buffer back out, along with the capability,
+    /// level and reset markers it was constructed with.
+    ///
+    /// Used by code that recycles raw buffers instead of letting them drop:
+    /// the underlying buffer keeps on living, just outside of this wrapper,
+    /// so the `Relevant` guard is disposed of rather than tripping its drop check.
+    pub(crate) fn into_raw(self) -> (B, C, L, R, FamilyId) {
+        let Buffer {
+            inner,
+            capability,
+            level,
+            reset,
+            family,
+            relevant,
+            ..
+        } = self;
+        relevant.dispose();
+        (inner, capability, level, reset, family)
+    }
+}
+
 impl<B, C, R> Buffer<B, C, InitialState, PrimaryLevel, R> {
     /// Begin recording command buffer.
     ///
     /// # Parameters
     ///
     /// `usage` - specifies usage of the command buffer. Possible types are `OneShot`, `MultiShot`.
+    /// `RenderPassContinue` is not accepted here: that usage only means
+    /// something for secondary buffers.
     pub fn begin<U>(self, usage: U) -> Buffer<B, C, RecordingState<U>, PrimaryLevel, R>
+    where
+        U: PrimaryUsage,
+    {
+        unimplemented!()
+    }
+}
+
+/// Describes the render pass a secondary command buffer is recorded to
+/// execute within. Required to `begin` a `SecondaryLevel` buffer.
+///
+/// # Parameters
+///
+/// `P` - render pass handle type.
+/// `F` - framebuffer handle type.
+#[derive(Clone, Copy, Debug)]
+pub struct Inheritance<'a, P: 'a, F: 'a = P> {
+    /// Render pass the secondary buffer will be executed within.
+    pub render_pass: &'a P,
+
+    /// Index of the subpass the secondary buffer will be executed within.
+    pub subpass: u32,
+
+    /// Framebuffer the secondary buffer will be executed with, when known ahead of time.
+    pub framebuffer: Option<&'a F>,
+}
+
+impl<B, C, R> Buffer<B, C, InitialState, SecondaryLevel, R> {
+    /// Begin recording a secondary command buffer, inheriting render pass
+    /// state described by `inheritance`.
+    ///
+    /// # Parameters
+    ///
+    /// `usage` - specifies usage of the command buffer. Use a `RenderPassContinue`-flagged
+    /// usage type to restrict recording to commands legal inside a subpass.
+    /// `inheritance` - render pass, subpass and (optionally) framebuffer this buffer will be executed within.
+    pub fn begin<U, P, F>(
+        self,
+        usage: U,
+        inheritance: Inheritance<P, F>,
+    ) -> Buffer<B, C, RecordingState<U>, SecondaryLevel, R>
     where
         U: Usage,
     {
@@ -151,13 +263,18 @@ impl<B, C, R> Buffer<B, C, InitialState, PrimaryLevel, R> {
 }
 
 /// Structure contains command buffer ready for submission.
+///
+/// `L` marks whether this was produced by a primary or secondary buffer, so
+/// that APIs expecting one cannot accidentally be handed the other - e.g.
+/// `execute_commands` only accepts a `Submit<S, SecondaryLevel>`.
 #[derive(Debug)]
-pub struct Submit<S> {
+pub struct Submit<S, L> {
     raw: S,
     family: FamilyId,
+    level: L,
 }
 
-impl<S> Submit<S> {
+impl<S, L> Submit<S, L> {
     /// Get family this submit is associated with.
     pub fn family(&self) -> FamilyId {
         self.family
@@ -177,7 +294,7 @@ where
     pub fn submit_once(
         self,
     ) -> (
-        Submit<B::Submit>,
+        Submit<B::Submit, PrimaryLevel>,
         Buffer<B, C, PendingState<InvalidState>, PrimaryLevel, R>,
     ) {
         unimplemented!()
@@ -192,13 +309,43 @@ where
     pub fn submit(
         self,
     ) -> (
-        Submit<B::Submit>,
+        Submit<B::Submit, PrimaryLevel>,
         Buffer<B, C, PendingState<ExecutableState<MultiShot<S>>>, PrimaryLevel, R>,
     ) {
         unimplemented!()
     }
 }
 
+impl<B, C, R> Buffer<B, C, ExecutableState<OneShot>, SecondaryLevel, R>
+where
+    B: CommandBuffer,
+{
+    /// Produce a `Submit` that a primary buffer's `execute_commands` can consume.
+    pub fn submit_once(
+        self,
+    ) -> (
+        Submit<B::Submit, SecondaryLevel>,
+        Buffer<B, C, PendingState<InvalidState>, SecondaryLevel, R>,
+    ) {
+        unimplemented!()
+    }
+}
+
+impl<B, C, S, R> Buffer<B, C, ExecutableState<MultiShot<S>>, SecondaryLevel, R>
+where
+    B: CommandBuffer,
+{
+    /// Produce a `Submit` that a primary buffer's `execute_commands` can consume.
+    pub fn submit(
+        self,
+    ) -> (
+        Submit<B::Submit, SecondaryLevel>,
+        Buffer<B, C, PendingState<ExecutableState<MultiShot<S>>>, SecondaryLevel, R>,
+    ) {
+        unimplemented!()
+    }
+}
+
 impl<B, C, N, L, R> Buffer<B, C, PendingState<N>, L, R> {
     /// Mark command buffer as complete.
     ///
@@ -246,6 +393,25 @@ where
     }
 }
 
+impl<B, C, U, R> Buffer<B, C, RecordingState<U>, PrimaryLevel, R>
+where
+    B: CommandBuffer,
+{
+    /// Execute secondary command buffers as part of this primary buffer.
+    ///
+    /// This allows a subpass to be recorded across multiple threads: each
+    /// thread records its own secondary buffer with `Inheritance`, and the
+    /// resulting `Submit`s are stitched together here. Only `Submit`s
+    /// produced by `SecondaryLevel` buffers type-check here; a primary
+    /// buffer's `Submit` is for queue submission, not execution.
+    pub fn execute_commands<S>(
+        &mut self,
+        submits: impl IntoIterator<Item = Submit<S, SecondaryLevel>>,
+    ) {
+        unimplemented!()
+    }
+}
+
 impl<'a, F: 'a, B> CommandBuffer for FrameBound<'a, F, B>
 where
     B: CommandBuffer,
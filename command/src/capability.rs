@@ -0,0 +1,44 @@
+//! Capability markers for command buffers.
+//!
+//! Queue families expose different subsets of commands. These zero-sized
+//! marker types and the `Supports` relationship mirror gfx-hal's
+//! strongly-typed command buffers, so that the `capability: C` field on
+//! `Buffer` can gate which recording methods are available at compile time
+//! instead of merely documenting which queue family the buffer came from.
+
+/// Capability of transfer-only queue families.
+/// Allows copy commands to be recorded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Transfer;
+
+/// Capability of compute queue families.
+/// Allows dispatch commands in addition to `Transfer` commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Compute;
+
+/// Capability of graphics queue families.
+/// Allows draw commands in addition to `Transfer` commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Graphics;
+
+/// Capability of general queue families.
+/// Allows all `Graphics` and `Compute` commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct General;
+
+/// `Supports<T>` is implemented for capability `C` whenever buffers with
+/// capability `C` are allowed to record commands that require capability `T`.
+pub trait Supports<T> {}
+
+impl Supports<Transfer> for Transfer {}
+
+impl Supports<Transfer> for Compute {}
+impl Supports<Compute> for Compute {}
+
+impl Supports<Transfer> for Graphics {}
+impl Supports<Graphics> for Graphics {}
+
+impl Supports<Transfer> for General {}
+impl Supports<Compute> for General {}
+impl Supports<Graphics> for General {}
+impl Supports<General> for General {}
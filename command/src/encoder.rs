@@ -0,0 +1,232 @@
+//! Encoder module docs.
+
+use buffer::OutsideRenderPass;
+use capability::{Compute, Graphics, Supports, Transfer};
+use device::CommandBuffer;
+use resource::image::{AccessType, Extent3D, Format, Image, ResourceState, SampleCountFlags};
+
+/// Encoders record commands into a raw command buffer of capability `C`.
+/// Methods that require a capability the buffer may not have are bounded by
+/// `Supports<Cap>`, so e.g. a transfer-only encoder cannot record draw or
+/// dispatch commands.
+pub trait Encoder<C> {
+    /// Raw command buffer type being encoded into.
+    type Buffer: CommandBuffer;
+
+    /// Get raw command buffer.
+    ///
+    /// # Safety
+    ///
+    /// Trait implementations can rely on this method only being used to record commands.
+    unsafe fn buffer(&mut self) -> &mut Self::Buffer;
+
+    /// Bind a compute pipeline for subsequent `dispatch` commands.
+    ///
+    /// Compute commands are illegal inside a render pass, so this requires
+    /// `Self: OutsideRenderPass`.
+    fn bind_compute_pipeline(&mut self)
+    where
+        C: Supports<Compute>,
+        Self: OutsideRenderPass,
+    {
+        unimplemented!()
+    }
+
+    /// Record a dispatch command.
+    ///
+    /// Compute commands are illegal inside a render pass, so this requires
+    /// `Self: OutsideRenderPass`.
+    fn dispatch(&mut self, group_count_x: u32, group_count_y: u32, group_count_z: u32)
+    where
+        C: Supports<Compute>,
+        Self: OutsideRenderPass,
+    {
+        unimplemented!()
+    }
+
+    /// Bind a graphics pipeline for subsequent `draw` commands.
+    fn bind_graphics_pipeline(&mut self)
+    where
+        C: Supports<Graphics>,
+    {
+        unimplemented!()
+    }
+
+    /// Record a draw command.
+    fn draw(&mut self, vertices: ::std::ops::Range<u32>, instances: ::std::ops::Range<u32>)
+    where
+        C: Supports<Graphics>,
+    {
+        unimplemented!()
+    }
+
+    /// Clear an image to a single value.
+    ///
+    /// Clears are illegal inside a render pass, so this requires
+    /// `Self: OutsideRenderPass`.
+    fn clear_image(&mut self)
+    where
+        C: Supports<Graphics>,
+        Self: OutsideRenderPass,
+    {
+        unimplemented!()
+    }
+
+    /// Record a buffer-to-buffer copy command.
+    ///
+    /// Copies are illegal inside a render pass, so this requires
+    /// `Self: OutsideRenderPass`.
+    fn copy_buffer(&mut self)
+    where
+        C: Supports<Transfer>,
+        Self: OutsideRenderPass,
+    {
+        unimplemented!()
+    }
+
+    /// Record an image-to-image copy command.
+    ///
+    /// Copies are illegal inside a render pass, so this requires
+    /// `Self: OutsideRenderPass`.
+    fn copy_image(&mut self)
+    where
+        C: Supports<Transfer>,
+        Self: OutsideRenderPass,
+    {
+        unimplemented!()
+    }
+
+    /// Blit `src_level` of `image` (at `src_extent`) into `dst_level` (at
+    /// `dst_extent`) with linear filtering, scaling to fit if the extents
+    /// differ.
+    ///
+    /// Blits are illegal inside a render pass, so this requires
+    /// `Self: OutsideRenderPass`.
+    fn blit_image<M, I>(
+        &mut self,
+        image: &Image<M, I>,
+        src_level: u32,
+        src_extent: Extent3D,
+        dst_level: u32,
+        dst_extent: Extent3D,
+    ) where
+        C: Supports<Graphics>,
+        Self: OutsideRenderPass,
+    {
+        let _ = (image, src_level, src_extent, dst_level, dst_extent);
+        unimplemented!()
+    }
+
+    /// Generate the mip chain of `image` from its level `0` data.
+    ///
+    /// `levels` must hold the image's real, persistently-tracked
+    /// `ResourceState`, one entry per mip level, carried over from whatever
+    /// previously wrote each level (e.g. after `Session::create_image_init`
+    /// level `0` holds data in the layout its usage implies, it is not
+    /// untracked). Passing freshly
+    /// created states here would make the first `transition()` believe the
+    /// level is still `Undefined`, and Vulkan permits discarding a resource's
+    /// contents on a transition out of `Undefined`, silently losing the
+    /// level's data.
+    ///
+    /// Each level `i` is blitted with linear filtering into level `i + 1` at
+    /// half the extent (rounded down, clamped to `1`), with the required
+    /// `TransferSrcOptimal` / `TransferDstOptimal` layout transitions inserted
+    /// between steps. The whole image is left in `ShaderReadOnlyOptimal`
+    /// afterwards.
+    ///
+    /// Fails without recording anything if the image's format does not
+    /// support linear-filter blitting or if the image is multisampled, since
+    /// neither can be blitted between mip levels.
+    ///
+    /// An image with at most one mip level has no chain to generate: it
+    /// returns `Ok(())` without these checks and without any transition.
+    ///
+    /// Blits are illegal inside a render pass, so this requires
+    /// `Self: OutsideRenderPass`.
+    fn generate_mipmaps<M, I>(
+        &mut self,
+        image: &Image<M, I>,
+        levels: &mut [ResourceState],
+    ) -> Result<(), GenerateMipmapsError>
+    where
+        C: Supports<Graphics>,
+        Self: OutsideRenderPass,
+    {
+        let info = image.info();
+
+        if info.mips <= 1 {
+            return Ok(());
+        }
+
+        if info.samples != SampleCountFlags::SAMPLE_COUNT_1 {
+            return Err(GenerateMipmapsError::Multisampled);
+        }
+
+        if !info.format.supports_linear_filter() {
+            return Err(GenerateMipmapsError::UnsupportedFormat(info.format));
+        }
+
+        debug_assert_eq!(
+            levels.len(),
+            info.mips as usize,
+            "one ResourceState per mip level is required"
+        );
+
+        // Each level is tracked independently since a blit step needs its
+        // source in `TransferSrcOptimal` and its destination in
+        // `TransferDstOptimal` at the same time, which a single whole-image
+        // `ResourceState` cannot represent.
+        let mut extent = info.extent;
+
+        for level in 0..info.mips - 1 {
+            let next_extent = Extent3D {
+                width: (extent.width / 2).max(1),
+                height: (extent.height / 2).max(1),
+                depth: (extent.depth / 2).max(1),
+            };
+
+            self.transition(&mut levels[level as usize], &[AccessType::TransferRead]);
+            self.transition(&mut levels[level as usize + 1], &[AccessType::TransferWrite]);
+            self.blit_image(image, level, extent, level + 1, next_extent);
+
+            extent = next_extent;
+        }
+
+        for level in levels {
+            self.transition(level, &[AccessType::FragmentShaderReadSampled]);
+        }
+
+        Ok(())
+    }
+
+    /// Transition a resource from whatever accesses `state` last recorded
+    /// into `next`, inserting a pipeline barrier and image layout transition
+    /// only when the accesses actually changed.
+    ///
+    /// Consecutive calls with an identical `next` are a no-op, so resources
+    /// do not need to be tracked by the caller beyond holding onto `state`.
+    ///
+    /// Vulkan restricts the barriers legal inside a render pass to the same
+    /// subpass's self-dependencies, which this general-purpose transition
+    /// does not model, so this requires `Self: OutsideRenderPass`.
+    fn transition(&mut self, state: &mut ResourceState, next: &[AccessType])
+    where
+        Self: OutsideRenderPass,
+    {
+        if let Some(barrier) = state.transition(next) {
+            let _ = barrier;
+            unimplemented!()
+        }
+    }
+}
+
+/// Error returned by `Encoder::generate_mipmaps` when the image cannot have
+/// its mip chain generated via blitting.
+#[derive(Clone, Copy, Debug)]
+pub enum GenerateMipmapsError {
+    /// The image's format does not support linear-filter blitting.
+    UnsupportedFormat(Format),
+    /// The image has more than one sample per texel and cannot be blitted.
+    Multisampled,
+}
@@ -0,0 +1,88 @@
+//! Command pool module docs.
+
+use buffer::{Buffer, IndividualReset, InitialState};
+use device::CommandBuffer;
+use family::FamilyId;
+
+/// Pool of recyclable command buffers for a single queue family, capability
+/// and level combination (capability and level are encoded by `C` and `L`, so
+/// buffers of another capability or level cannot be released into the pool;
+/// the family is a runtime value that `release` does not check).
+///
+/// Finished buffers handed back via `release` are kept around and reset in
+/// place instead of being dropped, so the next `acquire` can reuse an
+/// already-allocated raw command buffer instead of allocating a new one.
+#[derive(Debug)]
+pub struct CommandPool<B, C, L> {
+    family: FamilyId,
+    capability: C,
+    level: L,
+    free: Vec<B>,
+}
+
+impl<B, C, L> CommandPool<B, C, L>
+where
+    B: CommandBuffer,
+    C: Copy,
+    L: Copy,
+{
+    /// Create an empty pool for the given family, capability and level.
+    pub fn new(family: FamilyId, capability: C, level: L) -> Self {
+        CommandPool {
+            family,
+            capability,
+            level,
+            free: Vec::new(),
+        }
+    }
+
+    /// Family this pool allocates buffers from.
+    pub fn family(&self) -> FamilyId {
+        self.family
+    }
+
+    /// Acquire a command buffer in `InitialState`, reusing a recycled raw
+    /// buffer when one is available instead of allocating.
+    pub fn acquire(&mut self) -> Buffer<B, C, InitialState, L, IndividualReset> {
+        let raw = match self.free.pop() {
+            Some(raw) => raw,
+            None => Self::allocate_raw(),
+        };
+        Buffer::from_raw(raw, self.capability, self.level, IndividualReset, self.family)
+    }
+
+    /// Return a finished, individually-resettable buffer to the pool so a
+    /// later `acquire` can recycle it.
+    ///
+    /// The underlying raw buffer is reset in place. Buffers that fail the
+    /// "suitable for reuse" check (for example because the backend cannot
+    /// cheaply reset them) are destroyed instead of recycled.
+    ///
+    /// Either way the buffer is unwrapped via `into_raw` rather than simply
+    /// dropped: letting a `Buffer` go out of scope without disposing its
+    /// `Relevant` guard trips its drop-bomb panic.
+    pub fn release(&mut self, buffer: Buffer<B, C, InitialState, L, IndividualReset>) {
+        let (raw, _capability, _level, _reset, _family) = buffer.into_raw();
+        if Self::suitable_for_reuse_raw(&raw) {
+            self.free.push(Self::reset_raw(raw));
+        } else {
+            Self::destroy_raw(raw);
+        }
+    }
+
+    fn allocate_raw() -> B {
+        unimplemented!("allocate a new raw command buffer from the family")
+    }
+
+    fn reset_raw(_raw: B) -> B {
+        unimplemented!("reset the raw command buffer in place")
+    }
+
+    fn destroy_raw(_raw: B) {
+        unimplemented!("destroy the raw command buffer")
+    }
+
+    fn suitable_for_reuse_raw(_raw: &B) -> bool {
+        unimplemented!()
+    }
+}
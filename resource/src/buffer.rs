@@ -0,0 +1,69 @@
+//! Buffer usage, creation-info and wrapper.
+
+use memory::MemoryBlock;
+use relevant::Relevant;
+
+use escape::Escape;
+use SharingMode;
+
+bitflags! {
+    /// Bitmask specifying allowed usage of a buffer.
+    /// See Vulkan docs for detailed info:
+    /// <https://www.khronos.org/registry/vulkan/specs/1.1-extensions/man/html/VkBufferUsageFlagBits.html>
+    #[repr(transparent)]
+    pub struct UsageFlags: u32 {
+        /// Specifies that buffer can be used as source of transfer commands.
+        const TRANSFER_SRC = 0x00000001;
+        /// Specifies that buffer can be used as destination of transfer commands.
+        const TRANSFER_DST = 0x00000002;
+        /// Specifies that buffer can be used in a descriptor set as a uniform buffer.
+        const UNIFORM_BUFFER = 0x00000010;
+        /// Specifies that buffer can be used in a descriptor set as a storage buffer.
+        const STORAGE_BUFFER = 0x00000020;
+        /// Specifies that buffer is suitable for passing as an index buffer.
+        const INDEX_BUFFER = 0x00000040;
+        /// Specifies that buffer is suitable for passing as a vertex buffer.
+        const VERTEX_BUFFER = 0x00000080;
+        /// Specifies that buffer is suitable for passing as the buffer parameter to an indirect draw or dispatch.
+        const INDIRECT_BUFFER = 0x00000100;
+    }
+}
+
+/// Contains information required to create a buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CreateInfo {
+    /// Size of the buffer in bytes.
+    pub size: u64,
+
+    /// Intended usage flags. Limits memory types suitable for the buffer.
+    pub usage: UsageFlags,
+
+    /// Specifies command queues from which families can access the buffer.
+    pub sharing: SharingMode,
+}
+
+/// Generic buffer object wrapper.
+///
+/// # Parameters
+///
+/// `M` - type of the memory object of memory block.
+/// `B` - raw buffer type.
+#[derive(Debug)]
+pub struct Buffer<M, B> {
+    pub(super) inner: Escape<Inner<M, B>>,
+    pub(super) info: CreateInfo,
+}
+
+impl<M, B> Buffer<M, B> {
+    /// Get buffer creation info.
+    pub fn info(&self) -> &CreateInfo {
+        &self.info
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Inner<M, B> {
+    pub(super) block: MemoryBlock<M>,
+    pub(super) raw: B,
+    pub(super) relevant: Relevant,
+}
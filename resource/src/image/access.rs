@@ -0,0 +1,285 @@
+//! Access-type tracking used to automatically derive pipeline barriers and
+//! image layout transitions, modeled on the vk-sync approach.
+
+use super::Layout;
+
+bitflags! {
+    /// Bitmask specifying pipeline stages.
+    /// See Vulkan docs for detailed info:
+    /// <https://www.khronos.org/registry/vulkan/specs/1.1-extensions/man/html/VkPipelineStageFlagBits.html>
+    #[repr(transparent)]
+    pub struct PipelineStageFlags: u32 {
+        /// Specifies the stage of the pipeline where any commands are initially received.
+        const TOP_OF_PIPE = 0x00000001;
+        /// Specifies the stage of the pipeline where vertex shaders execute.
+        const VERTEX_SHADER = 0x00000008;
+        /// Specifies the stage of the pipeline where fragment shaders execute.
+        const FRAGMENT_SHADER = 0x00000080;
+        /// Specifies the stage of the pipeline after blending where the final color values are output.
+        const COLOR_ATTACHMENT_OUTPUT = 0x00000400;
+        /// Specifies the stage of the pipeline where compute shaders execute.
+        const COMPUTE_SHADER = 0x00000800;
+        /// Specifies the execution of copy and blit commands.
+        const TRANSFER = 0x00001000;
+        /// Specifies the final stage of the pipeline where operations generated by all commands complete execution.
+        const BOTTOM_OF_PIPE = 0x00002000;
+        /// Specifies a pseudo-stage indicating execution on the host.
+        const HOST = 0x00004000;
+    }
+}
+
+bitflags! {
+    /// Bitmask specifying memory access types that will participate in a memory dependency.
+    /// See Vulkan docs for detailed info:
+    /// <https://www.khronos.org/registry/vulkan/specs/1.1-extensions/man/html/VkAccessFlagBits.html>
+    #[repr(transparent)]
+    pub struct AccessFlags: u32 {
+        /// Specifies read access to a shader-accessible resource.
+        const SHADER_READ = 0x00000020;
+        /// Specifies read access to a color attachment.
+        const COLOR_ATTACHMENT_READ = 0x00000080;
+        /// Specifies write access to a color attachment.
+        const COLOR_ATTACHMENT_WRITE = 0x00000100;
+        /// Specifies read access to an image or buffer in a copy operation.
+        const TRANSFER_READ = 0x00000800;
+        /// Specifies write access to an image or buffer in a copy operation.
+        const TRANSFER_WRITE = 0x00001000;
+        /// Specifies write access performed by the host.
+        const HOST_WRITE = 0x00004000;
+        /// Specifies read access via non-specific access types, used for presentation.
+        const MEMORY_READ = 0x00008000;
+    }
+}
+
+/// A particular way a resource is accessed by the device.
+///
+/// Each variant maps statically to a triple of pipeline stage, memory access
+/// and the image layout required while that access is performed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessType {
+    /// Read as a sampled image by a vertex shader.
+    VertexShaderReadSampled,
+    /// Read as a sampled image by a fragment shader.
+    FragmentShaderReadSampled,
+    /// Read as a sampled image by a compute shader.
+    ComputeShaderReadSampled,
+    /// Read as a color or resolve attachment, e.g. for blending.
+    ColorAttachmentRead,
+    /// Written as a color or resolve attachment.
+    ColorAttachmentWrite,
+    /// Read as the source of a transfer (copy or blit) command.
+    TransferRead,
+    /// Written as the destination of a transfer (copy or blit) command.
+    TransferWrite,
+    /// Written directly by the host.
+    HostWrite,
+    /// Presented to the surface.
+    Present,
+}
+
+impl AccessType {
+    /// Pipeline stage at which this access is performed.
+    pub fn stage(&self) -> PipelineStageFlags {
+        match *self {
+            AccessType::VertexShaderReadSampled => PipelineStageFlags::VERTEX_SHADER,
+            AccessType::FragmentShaderReadSampled => PipelineStageFlags::FRAGMENT_SHADER,
+            AccessType::ComputeShaderReadSampled => PipelineStageFlags::COMPUTE_SHADER,
+            AccessType::ColorAttachmentRead | AccessType::ColorAttachmentWrite => {
+                PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT
+            }
+            AccessType::TransferRead | AccessType::TransferWrite => PipelineStageFlags::TRANSFER,
+            AccessType::HostWrite => PipelineStageFlags::HOST,
+            AccessType::Present => PipelineStageFlags::BOTTOM_OF_PIPE,
+        }
+    }
+
+    /// Memory access performed by this access.
+    pub fn access(&self) -> AccessFlags {
+        match *self {
+            AccessType::VertexShaderReadSampled
+            | AccessType::FragmentShaderReadSampled
+            | AccessType::ComputeShaderReadSampled => AccessFlags::SHADER_READ,
+            AccessType::ColorAttachmentRead => AccessFlags::COLOR_ATTACHMENT_READ,
+            AccessType::ColorAttachmentWrite => AccessFlags::COLOR_ATTACHMENT_WRITE,
+            AccessType::TransferRead => AccessFlags::TRANSFER_READ,
+            AccessType::TransferWrite => AccessFlags::TRANSFER_WRITE,
+            AccessType::HostWrite => AccessFlags::HOST_WRITE,
+            AccessType::Present => AccessFlags::MEMORY_READ,
+        }
+    }
+
+    /// Image layout required while this access is performed.
+    pub fn layout(&self) -> Layout {
+        match *self {
+            AccessType::VertexShaderReadSampled
+            | AccessType::FragmentShaderReadSampled
+            | AccessType::ComputeShaderReadSampled => Layout::ShaderReadOnlyOptimal,
+            AccessType::ColorAttachmentRead | AccessType::ColorAttachmentWrite => {
+                Layout::ColorAttachmentOptimal
+            }
+            AccessType::TransferRead => Layout::TransferSrcOptimal,
+            AccessType::TransferWrite => Layout::TransferDstOptimal,
+            AccessType::HostWrite => Layout::Preinitialized,
+            AccessType::Present => Layout::PresentSrc,
+        }
+    }
+
+    /// Whether this access writes to the resource.
+    pub fn is_write(&self) -> bool {
+        match *self {
+            AccessType::ColorAttachmentWrite | AccessType::TransferWrite | AccessType::HostWrite => {
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Describes the pipeline barrier and optional layout transition required to
+/// move a resource from its previously tracked accesses into a new set of accesses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Barrier {
+    /// Combined stages of all previous accesses.
+    pub src_stage: PipelineStageFlags,
+    /// Combined stages of all next accesses.
+    pub dst_stage: PipelineStageFlags,
+    /// Set when a memory barrier is required because of a write-after-write
+    /// or read-after-write hazard.
+    pub memory_barrier: bool,
+    /// Set when the resource must be transitioned between image layouts,
+    /// carrying the old and new layout.
+    pub layout_transition: Option<(Layout, Layout)>,
+}
+
+/// Tracks the set of accesses a resource was last used with, so the next use
+/// can be transitioned into with a single, minimal barrier instead of
+/// requiring callers to pick stage and access masks by hand.
+#[derive(Clone, Debug, Default)]
+pub struct ResourceState {
+    previous: Vec<AccessType>,
+}
+
+impl ResourceState {
+    /// Create state for a resource that has not been accessed yet.
+    pub fn new() -> Self {
+        ResourceState {
+            previous: Vec::new(),
+        }
+    }
+
+    /// Compute the barrier required to transition from the tracked accesses
+    /// to `next` and record `next` as the new tracked accesses.
+    ///
+    /// Returns `None` when `next` is identical to the previously tracked
+    /// accesses, in which case the transition is a no-op and no barrier needs
+    /// to be recorded.
+    pub fn transition(&mut self, next: &[AccessType]) -> Option<Barrier> {
+        if self.previous == next {
+            return None;
+        }
+
+        // A resource that has never been accessed is implicitly in the
+        // `Undefined` layout, coming from the `TOP_OF_PIPE` pseudo-stage with
+        // no prior memory access to wait on. Falling through to an empty
+        // `src_stage`/no layout transition here would produce an illegal
+        // barrier and silently skip the `Undefined` -> first-use transition.
+        let src_stage = if self.previous.is_empty() {
+            PipelineStageFlags::TOP_OF_PIPE
+        } else {
+            self.previous
+                .iter()
+                .fold(PipelineStageFlags::empty(), |acc, access| {
+                    acc | access.stage()
+                })
+        };
+        let dst_stage = next
+            .iter()
+            .fold(PipelineStageFlags::empty(), |acc, access| {
+                acc | access.stage()
+            });
+
+        let memory_barrier = self.previous.iter().any(AccessType::is_write);
+
+        let old_layout = self
+            .previous
+            .first()
+            .map(AccessType::layout)
+            .unwrap_or(Layout::Undefined);
+        let new_layout = next.first().map(AccessType::layout);
+        let layout_transition = match new_layout {
+            Some(new) if new != old_layout => Some((old_layout, new)),
+            _ => None,
+        };
+
+        self.previous = next.to_vec();
+
+        Some(Barrier {
+            src_stage,
+            dst_stage,
+            memory_barrier,
+            layout_transition,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_accesses_are_a_no_op() {
+        let mut state = ResourceState::new();
+        state.transition(&[AccessType::TransferRead]);
+        assert_eq!(state.transition(&[AccessType::TransferRead]), None);
+    }
+
+    #[test]
+    fn first_use_transitions_out_of_undefined() {
+        let mut state = ResourceState::new();
+        let barrier = state.transition(&[AccessType::TransferWrite]).unwrap();
+
+        assert_eq!(barrier.src_stage, PipelineStageFlags::TOP_OF_PIPE);
+        assert_eq!(barrier.dst_stage, PipelineStageFlags::TRANSFER);
+        assert!(!barrier.memory_barrier);
+        assert_eq!(
+            barrier.layout_transition,
+            Some((Layout::Undefined, Layout::TransferDstOptimal))
+        );
+    }
+
+    #[test]
+    fn write_after_write_requires_a_memory_barrier() {
+        let mut state = ResourceState::new();
+        state.transition(&[AccessType::TransferWrite]);
+        let barrier = state
+            .transition(&[AccessType::TransferWrite, AccessType::HostWrite])
+            .unwrap();
+
+        assert!(barrier.memory_barrier);
+    }
+
+    #[test]
+    fn read_after_read_does_not_require_a_memory_barrier() {
+        let mut state = ResourceState::new();
+        state.transition(&[AccessType::TransferRead]);
+        let barrier = state
+            .transition(&[AccessType::TransferRead, AccessType::FragmentShaderReadSampled])
+            .unwrap();
+
+        assert!(!barrier.memory_barrier);
+    }
+
+    #[test]
+    fn layout_change_is_reported() {
+        let mut state = ResourceState::new();
+        state.transition(&[AccessType::TransferWrite]);
+        let barrier = state
+            .transition(&[AccessType::FragmentShaderReadSampled])
+            .unwrap();
+
+        assert_eq!(
+            barrier.layout_transition,
+            Some((Layout::TransferDstOptimal, Layout::ShaderReadOnlyOptimal))
+        );
+    }
+}
@@ -1,10 +1,14 @@
 //! Image usage, format, kind, extent, creation-info and wrappers.
 
+mod access;
 pub mod format;
 mod usage;
+mod view;
 
+pub use self::access::{AccessType, AccessFlags, Barrier, PipelineStageFlags, ResourceState};
 pub use self::format::Format;
 pub use self::usage::*;
+pub use self::view::{ImageView, SubresourceRange, ViewCreateInfo, ViewCreationError, ViewKind};
 
 use memory::MemoryBlock;
 use relevant::Relevant;
@@ -188,6 +192,13 @@ pub struct Image<M, I> {
     pub(super) info: CreateInfo,
 }
 
+impl<M, I> Image<M, I> {
+    /// Get image creation info.
+    pub fn info(&self) -> &CreateInfo {
+        &self.info
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct Inner<M, I> {
     pub(super) block: MemoryBlock<M>,
@@ -0,0 +1,154 @@
+//! Session module docs.
+
+use buffer::{self, Buffer};
+use escape::Escape;
+use image::{self, Image};
+use SharingMode;
+
+/// The raw device calls `Session` needs to turn a `CreateInfo` and some bytes
+/// into an initialized resource: allocating images/buffers, writing host
+/// data into mapped memory, and recording and submitting the one-shot
+/// transfer command buffer that copies it into place.
+pub trait Device {
+    /// Raw image handle type.
+    type Image;
+    /// Raw buffer handle type.
+    type Buffer;
+    /// Memory object type backing allocations.
+    type Memory;
+    /// Fence signaled once a submission the device records for us completes.
+    type Fence;
+
+    /// Allocate a device-local image per `info`.
+    fn create_image(&mut self, info: &image::CreateInfo) -> Image<Self::Memory, Self::Image>;
+
+    /// Allocate a buffer per `info`.
+    fn create_buffer(&mut self, info: &buffer::CreateInfo) -> Buffer<Self::Memory, Self::Buffer>;
+
+    /// Map `buffer`'s memory and copy `data` into it.
+    fn write_mapped(&mut self, buffer: &Buffer<Self::Memory, Self::Buffer>, data: &[u8]);
+
+    /// Record and submit a command buffer that copies `staging` into `image`,
+    /// transitioning it to the layout its usage implies.
+    ///
+    /// Returns a fence signaled once the device is done reading `staging`.
+    fn copy_buffer_to_image(
+        &mut self,
+        staging: &Buffer<Self::Memory, Self::Buffer>,
+        image: &Image<Self::Memory, Self::Image>,
+        info: &image::CreateInfo,
+    ) -> Self::Fence;
+
+    /// Record and submit a command buffer that copies `staging` into `buffer`.
+    ///
+    /// Returns a fence signaled once the device is done reading `staging`.
+    fn copy_buffer_to_buffer(
+        &mut self,
+        staging: &Buffer<Self::Memory, Self::Buffer>,
+        buffer: &Buffer<Self::Memory, Self::Buffer>,
+    ) -> Self::Fence;
+
+    /// Whether `fence` has been signaled by the device yet.
+    fn is_signaled(&self, fence: &Self::Fence) -> bool;
+}
+
+/// Higher-level entry point for creating device resources.
+///
+/// `Session` wraps a `Device` and exposes one-call uploads: given a
+/// `CreateInfo` and some data, it allocates the device-local resource,
+/// allocates a temporary host-visible staging buffer, copies the data into
+/// it, records and submits a transfer command buffer that copies from the
+/// staging buffer into the resource (transitioning images to the requested
+/// usage layout along the way), and registers the staging buffer with the
+/// `Escape` deferred-drop machinery so it is only freed once that copy has
+/// completed.
+pub struct Session<D: Device> {
+    device: D,
+
+    /// Staging buffers whose upload fence has not signaled yet, kept alive
+    /// (via `Escape`, so dropping them is always safe) until `collect` sees
+    /// their fence complete.
+    pending: Vec<(D::Fence, Escape<Buffer<D::Memory, D::Buffer>>)>,
+}
+
+impl<D: Device> Session<D> {
+    /// Wrap a device in a session.
+    pub fn new(device: D) -> Self {
+        Session {
+            device,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Drop staging buffers whose upload has completed.
+    ///
+    /// Call this periodically (e.g. once per frame) to reclaim the memory
+    /// `create_image_init`/`create_buffer_init` deferred freeing until their
+    /// transfer finished.
+    pub fn collect(&mut self) {
+        let device = &self.device;
+        self.pending.retain(|(fence, _)| !device.is_signaled(fence));
+    }
+
+    /// Create an image and fill it with `data` via a temporary staging buffer.
+    pub fn create_image_init(
+        &mut self,
+        info: &image::CreateInfo,
+        data: &[u8],
+    ) -> Image<D::Memory, D::Image> {
+        // The image is about to be the destination of the copy below, so it
+        // must be created as a valid one regardless of what the caller asked
+        // for it to be used as.
+        let mut info = *info;
+        info.usage |= image::UsageFlags::TRANSFER_DST;
+
+        let image = self.device.create_image(&info);
+
+        let staging_info = buffer::CreateInfo {
+            size: data.len() as u64,
+            usage: buffer::UsageFlags::TRANSFER_SRC,
+            sharing: info.sharing,
+        };
+        let staging = self.device.create_buffer(&staging_info);
+        self.device.write_mapped(&staging, data);
+
+        let fence = self.device.copy_buffer_to_image(&staging, &image, &info);
+
+        // The device may still be reading `staging` for the copy we just
+        // submitted, so its real destruction has to wait for `fence`.
+        // `Escape` makes dropping it in the meantime harmless either way.
+        self.pending.push((fence, Escape::new(staging)));
+
+        image
+    }
+
+    /// Create a buffer and fill it with `data` via a temporary staging buffer.
+    pub fn create_buffer_init(
+        &mut self,
+        data: &[u8],
+        usage: buffer::UsageFlags,
+    ) -> Buffer<D::Memory, D::Buffer> {
+        // The buffer is about to be the destination of the copy below, so it
+        // must be created as a valid one regardless of what the caller asked
+        // for it to be used as.
+        let info = buffer::CreateInfo {
+            size: data.len() as u64,
+            usage: usage | buffer::UsageFlags::TRANSFER_DST,
+            sharing: SharingMode::Exclusive,
+        };
+        let target = self.device.create_buffer(&info);
+
+        let staging_info = buffer::CreateInfo {
+            size: info.size,
+            usage: buffer::UsageFlags::TRANSFER_SRC,
+            sharing: SharingMode::Exclusive,
+        };
+        let staging = self.device.create_buffer(&staging_info);
+        self.device.write_mapped(&staging, data);
+
+        let fence = self.device.copy_buffer_to_buffer(&staging, &target);
+        self.pending.push((fence, Escape::new(staging)));
+
+        target
+    }
+}